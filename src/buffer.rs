@@ -353,7 +353,7 @@ impl Buffer {
                 y,
                 span.content.as_ref(),
                 remaining_cols as usize,
-                span.style,
+                line.style.patch(span.style),
             );
             let w = pos.0.saturating_sub(x);
             x = pos.0;
@@ -13,7 +13,39 @@ pub struct Geometry {
 impl Geometry {
     /// Initialize a Geometry with x=0, y=0,
     /// this works with the assumption in regard to the zellij host
+    ///
+    /// **Note the argument order is `(rows, cols)`**, which matches `ZellijPlugin::render(rows, cols)`,
+    /// see [`Geometry::from_size`] for details.
     pub fn new(rows: u16, cols: u16) -> Self {
+        Self::from_size(rows, cols)
+    }
+
+    /// Initialize a Geometry at x=0, y=0 from the size zellij hands to `ZellijPlugin::render`.
+    ///
+    /// # Argument order
+    ///
+    /// The arguments are **`(rows, cols)`**, i.e. height first and width second. This is the
+    /// same order zellij uses, but the opposite of the usual `(width, height)` convention, so
+    /// double check call sites when converting from other libraries.
+    ///
+    /// # Panics
+    ///
+    /// In debug builds, panics if `rows * cols` does not fit in a `u16`, as [`Geometry::area`]
+    /// would otherwise be silently clamped and the [`Buffer`](crate::buffer::Buffer) built from
+    /// it would be too small.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use zellij_widgets::prelude::*;
+    /// let geometry = Geometry::from_size(10, 80);
+    /// assert_eq!(geometry.rows, 10);
+    /// assert_eq!(geometry.cols, 80);
+    /// ```
+    pub fn from_size(rows: u16, cols: u16) -> Self {
+        debug_assert!(
+            rows.checked_mul(cols).is_some(),
+            "Geometry area overflows u16: rows={rows}, cols={cols}"
+        );
         Self::init(0, 0, rows, cols)
     }
 
@@ -111,6 +143,54 @@ impl Geometry {
             }
         }
     }
+
+    /// Splits the gemo into a left and a right part, `col` columns from the left edge.
+    ///
+    /// The offset is clamped to the width of the gemo, so splitting at `0` or beyond `cols`
+    /// returns an empty part on one side.
+    ///
+    /// # Visual representation:
+    /// ```ignore
+    /// +--------+--------+
+    /// |  left  | right  |
+    /// |        |        |
+    /// +--------+--------+
+    ///          ^ col
+    /// ```
+    pub fn split_horizontal_at(self, col: u16) -> (Geometry, Geometry) {
+        let col = col.min(self.cols);
+        let left = Geometry { cols: col, ..self };
+        let right = Geometry {
+            x: self.x.saturating_add(col),
+            cols: self.cols - col,
+            ..self
+        };
+        (left, right)
+    }
+
+    /// Splits the gemo into a top and a bottom part, `row` rows from the top edge.
+    ///
+    /// The offset is clamped to the height of the gemo, so splitting at `0` or beyond `rows`
+    /// returns an empty part on one side.
+    ///
+    /// # Visual representation:
+    /// ```ignore
+    /// +-----------------+
+    /// |       top       |
+    /// +-----------------+ <- row
+    /// |     bottom      |
+    /// +-----------------+
+    /// ```
+    pub fn split_vertical_at(self, row: u16) -> (Geometry, Geometry) {
+        let row = row.min(self.rows);
+        let top = Geometry { rows: row, ..self };
+        let bottom = Geometry {
+            y: self.y.saturating_add(row),
+            rows: self.rows - row,
+            ..self
+        };
+        (top, bottom)
+    }
 }
 
 #[cfg(test)]
@@ -126,6 +206,25 @@ mod tests {
         assert_eq!(geometry.cols, 20);
     }
 
+    #[test]
+    fn test_from_size_field_order() {
+        let geometry = Geometry::from_size(3, 7);
+        assert_eq!(geometry.x, 0);
+        assert_eq!(geometry.y, 0);
+        assert_eq!(geometry.rows, 3);
+        assert_eq!(geometry.cols, 7);
+        assert_eq!(geometry.bottom(), 3);
+        assert_eq!(geometry.right(), 7);
+        assert_eq!(geometry, Geometry::new(3, 7));
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "Geometry area overflows u16")]
+    fn test_from_size_overflow() {
+        Geometry::from_size(300, 300);
+    }
+
     #[test]
     fn test_area() {
         let geometry = Geometry::new(10, 20);
@@ -178,4 +277,53 @@ mod tests {
         assert_eq!(inner_geometry.rows, 6);
         assert_eq!(inner_geometry.cols, 16);
     }
+
+    #[test]
+    fn test_split_horizontal_at() {
+        let geometry = Geometry::init(2, 3, 10, 20);
+        let (left, right) = geometry.split_horizontal_at(5);
+        assert_eq!(left, Geometry::init(2, 3, 10, 5));
+        assert_eq!(right, Geometry::init(7, 3, 10, 15));
+    }
+
+    #[test]
+    fn test_split_horizontal_at_edges() {
+        let geometry = Geometry::init(2, 3, 10, 20);
+
+        let (left, right) = geometry.split_horizontal_at(0);
+        assert!(left.is_empty());
+        assert_eq!(right, geometry);
+
+        let (left, right) = geometry.split_horizontal_at(20);
+        assert_eq!(left, geometry);
+        assert!(right.is_empty());
+        assert_eq!(right.x, 22);
+
+        // Offsets beyond the width are clamped
+        let (left, right) = geometry.split_horizontal_at(100);
+        assert_eq!(left, geometry);
+        assert_eq!(right, Geometry::init(22, 3, 10, 0));
+    }
+
+    #[test]
+    fn test_split_vertical_at() {
+        let geometry = Geometry::init(2, 3, 10, 20);
+        let (top, bottom) = geometry.split_vertical_at(4);
+        assert_eq!(top, Geometry::init(2, 3, 4, 20));
+        assert_eq!(bottom, Geometry::init(2, 7, 6, 20));
+    }
+
+    #[test]
+    fn test_split_vertical_at_edges() {
+        let geometry = Geometry::init(2, 3, 10, 20);
+
+        let (top, bottom) = geometry.split_vertical_at(0);
+        assert!(top.is_empty());
+        assert_eq!(bottom, geometry);
+
+        // Offsets beyond the height are clamped
+        let (top, bottom) = geometry.split_vertical_at(100);
+        assert_eq!(top, geometry);
+        assert_eq!(bottom, Geometry::init(2, 13, 0, 20));
+    }
 }
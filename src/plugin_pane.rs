@@ -32,6 +32,15 @@ use crate::{
 };
 
 use std::io::{self, Write};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// The default maximum number of cells a [`PluginPane`] buffer can hold.
+///
+/// A [`Geometry`] area can not exceed `u16::MAX` cells, so larger panes are always clamped.
+pub const DEFAULT_MAX_CELLS: usize = u16::MAX as usize;
+
+/// Whether the clamping warning of [`PluginPane::with_max_cells`] has been printed.
+static CLAMP_WARNED: AtomicBool = AtomicBool::new(false);
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct CompletedFrame<'a> {
@@ -67,14 +76,55 @@ where
 {
     /// Set up a new `PluginPane` with the given writer and dimensions.
     /// You can assume that it starts at (0, 0) with rows and cols from `ZellijPlugin::render`
+    ///
+    /// **Note the argument order is `(rows, cols)`**, pass them in the same order
+    /// `ZellijPlugin::render(rows, cols)` receives them, see [`Geometry::from_size`].
+    ///
+    /// Panes larger than [`DEFAULT_MAX_CELLS`] are clamped, see [`PluginPane::with_max_cells`].
     pub fn new(writer: W, rows: u16, cols: u16) -> Self {
+        Self::with_max_cells(writer, rows, cols, DEFAULT_MAX_CELLS)
+    }
+
+    /// Set up a new `PluginPane` like [`PluginPane::new`], guarding against huge panes.
+    ///
+    /// If `rows * cols` exceeds `max_cells`, the rendered area is clamped to fit, dropping rows
+    /// first, to prevent a runaway allocation in pathological sizes (e.g. a detached session).
+    /// `max_cells` itself can not exceed [`DEFAULT_MAX_CELLS`].
+    ///
+    /// A warning is printed to stderr the first time a pane is clamped, since panes are usually
+    /// rebuilt on every render. Compare [`PluginPane::area`] with the requested size to detect
+    /// clamping on every call.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use zellij_widgets::prelude::*;
+    /// let pane = PluginPane::with_max_cells(std::io::stdout(), 100, 100, 1000);
+    /// assert_eq!(pane.area(), Geometry::new(10, 100));
+    /// ```
+    pub fn with_max_cells(writer: W, rows: u16, cols: u16, max_cells: usize) -> Self {
+        let (clamped_rows, clamped_cols) = clamp_to_max_cells(rows, cols, max_cells);
+        if (clamped_rows, clamped_cols) != (rows, cols)
+            && !CLAMP_WARNED.swap(true, Ordering::Relaxed)
+        {
+            eprintln!(
+                "zellij_widgets: pane of {rows} rows x {cols} cols exceeds {max_cells} cells, \
+                 clamped to {clamped_rows} rows x {clamped_cols} cols"
+            );
+        }
+
+        let geom = Geometry::from_size(clamped_rows, clamped_cols);
         Self {
             writer,
-            geom: Geometry::new(rows, cols),
-            buffer: Buffer::empty(Geometry::new(rows, cols)),
+            geom,
+            buffer: Buffer::empty(geom),
         }
     }
 
+    /// The area rendered by the plugin pane, after clamping.
+    pub fn area(&self) -> Geometry {
+        self.geom
+    }
+
     /// An important function that flushes the buffer, and it is also where the magic happens,
     /// such as setting foreground and background colors
     pub fn flush_buffer(&mut self) -> io::Result<()> {
@@ -163,6 +213,18 @@ where
     }
 }
 
+/// Clamp `rows` and `cols` so that `rows * cols` does not exceed `max_cells`, which is itself
+/// capped to [`DEFAULT_MAX_CELLS`]. Rows are dropped first, so the pane keeps its width if possible.
+fn clamp_to_max_cells(rows: u16, cols: u16, max_cells: usize) -> (u16, u16) {
+    let max_cells = max_cells.min(DEFAULT_MAX_CELLS);
+    if rows as usize * cols as usize <= max_cells {
+        return (rows, cols);
+    }
+    let cols = cols.min(max_cells as u16);
+    let rows = rows.min(max_cells.checked_div(cols as usize).unwrap_or(0) as u16);
+    (rows, cols)
+}
+
 /// The `ModifierDiff` struct is used to calculate the difference between two `Modifier`
 /// values. This is useful when updating the terminal display, as it allows for more
 /// efficient updates by only sending the necessary changes.
@@ -284,6 +346,46 @@ mod tests {
         assert_buffer_content!(plugin_pane.buffer, epxect);
     }
 
+    #[test]
+    fn test_plugin_pane_size_order() {
+        let plugin_pane = PluginPane::new(io::stdout(), 5, 40);
+        assert_eq!(plugin_pane.geom.rows, 5);
+        assert_eq!(plugin_pane.geom.cols, 40);
+        assert_eq!(plugin_pane.buffer.area, Geometry::from_size(5, 40));
+        assert_eq!(plugin_pane.buffer.pos_of(39), (39, 0));
+    }
+
+    #[test]
+    fn test_plugin_pane_max_cells_guard() {
+        // Keep the clamping warning out of the test output
+        CLAMP_WARNED.store(true, Ordering::Relaxed);
+
+        // 300 * 300 cells doesn't fit in the default guard
+        let plugin_pane = PluginPane::new(io::stdout(), 300, 300);
+        assert_eq!(plugin_pane.area(), Geometry::new(218, 300));
+        assert_eq!(plugin_pane.buffer.content().len(), 218 * 300);
+        assert!(plugin_pane.buffer.content().len() <= DEFAULT_MAX_CELLS);
+
+        let plugin_pane = PluginPane::with_max_cells(io::stdout(), 20, 20, 100);
+        assert_eq!(plugin_pane.area(), Geometry::new(5, 20));
+        assert_eq!(plugin_pane.buffer.content().len(), 100);
+
+        // Panes within the guard are left untouched
+        let plugin_pane = PluginPane::with_max_cells(io::stdout(), 10, 10, 100);
+        assert_eq!(plugin_pane.area(), Geometry::new(10, 10));
+    }
+
+    #[test]
+    fn test_clamp_to_max_cells() {
+        assert_eq!(clamp_to_max_cells(10, 10, 100), (10, 10));
+        assert_eq!(clamp_to_max_cells(10, 10, 50), (5, 10));
+        // The width alone exceeds the guard
+        assert_eq!(clamp_to_max_cells(10, 200, 100), (1, 100));
+        assert_eq!(clamp_to_max_cells(10, 10, 0), (0, 0));
+        // The guard can't be raised above what a Geometry can hold
+        assert_eq!(clamp_to_max_cells(u16::MAX, 2, usize::MAX), (32767, 2));
+    }
+
     #[test]
     fn test_get_frame() {
         let mut plugin_pane = PluginPane::new(io::stdout(), 20, 20);
@@ -14,8 +14,9 @@ pub use crate::{
     text::{self, Line, Masked, Span, StyledGrapheme, Text},
     title::{self, Position, Title},
     uis::{
-        self, Block, BorderOptions, BorderType, Borders, Erase, Gauge, HighlightStyle,
-        HighlightSymbol, List, ListItem, ListState, Padding, Paragraph, ScrollDirection, Scrollbar,
+        self, Accordion, AccordionSection, AccordionState, Block, BorderOptions, BorderType,
+        Borders, Erase, Gauge, HighlightStyle, HighlightSymbol, List, ListItem, ListState, LogView,
+        LogViewState, Padding, Paragraph, ScrollDirection, ScrollView, ScrollViewState, Scrollbar,
         ScrollbarOrientation, ScrollbarState, Tab, TabState, Wrap,
     },
     widget::{StateWidget, Widget},
@@ -266,6 +266,26 @@ impl Style {
 
         self
     }
+
+    /// Dims the foreground color by blending it toward the given background color.
+    ///
+    /// This is a more reliable alternative to [`Modifier::DIM`], which is rendered inconsistently
+    /// across terminals, e.g. for greying out disabled items. `amount` ranges from `0.0` (no
+    /// change) to `1.0` (foreground becomes `bg`), see [`Color::lerp`]. The style is returned
+    /// unchanged if it has no foreground color.
+    ///
+    /// ## Examples
+    /// ```
+    /// # use zellij_widgets::prelude::*;
+    ///
+    /// let style = Style::default().fg(Color::White).dimmed_toward(Color::Black, 0.5);
+    /// assert_eq!(style.fg, Some(Color::Rgb { r: 128, g: 128, b: 128 }));
+    /// ```
+    #[must_use = "`dimmed_toward` returns the modified style without modifying the original"]
+    pub fn dimmed_toward(mut self, bg: Color, amount: f32) -> Style {
+        self.fg = self.fg.map(|fg| fg.lerp(bg, amount));
+        self
+    }
 }
 
 impl_display!(for SetForegroundColor);
@@ -316,6 +336,20 @@ mod tests {
         }
     }
 
+    #[test]
+    fn dimmed_toward_blends_fg() {
+        let style = Style::default()
+            .fg(Color::Red)
+            .bg(Color::Black)
+            .dimmed_toward(Color::Black, 0.5);
+        assert_eq!(style.fg, Some(Color::Rgb { r: 128, g: 0, b: 0 }));
+        // Only the foreground is affected
+        assert_eq!(style.bg, Some(Color::Black));
+
+        let style = Style::default().dimmed_toward(Color::Black, 0.5);
+        assert_eq!(style, Style::default());
+    }
+
     #[test]
     fn combine_individual_modifiers() {
         use crate::{buffer::Buffer, layout::Geometry};
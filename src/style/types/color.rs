@@ -92,6 +92,26 @@ pub enum Color {
     AnsiValue(u8),
 }
 
+/// The 16 base colors, indexed by their 8 bit ANSI value.
+const BASE_COLORS: [Color; 16] = [
+    Color::Black,       // 0
+    Color::DarkRed,     // 1
+    Color::DarkGreen,   // 2
+    Color::DarkYellow,  // 3
+    Color::DarkBlue,    // 4
+    Color::DarkMagenta, // 5
+    Color::DarkCyan,    // 6
+    Color::Gray,        // 7
+    Color::DarkGray,    // 8
+    Color::Red,         // 9
+    Color::Green,       // 10
+    Color::Yellow,      // 11
+    Color::Blue,        // 12
+    Color::Magenta,     // 13
+    Color::Cyan,        // 14
+    Color::White,       // 15
+];
+
 impl Color {
     /// Parses an ANSI color sequence.
     ///
@@ -121,28 +141,10 @@ impl Color {
             5 => {
                 let n = parse_next_u8(values)?;
 
-                use Color::*;
-                [
-                    Black,       // 0
-                    DarkRed,     // 1
-                    DarkGreen,   // 2
-                    DarkYellow,  // 3
-                    DarkBlue,    // 4
-                    DarkMagenta, // 5
-                    DarkCyan,    // 6
-                    Gray,        // 7
-                    DarkGray,    // 8
-                    Red,         // 9
-                    Green,       // 10
-                    Yellow,      // 11
-                    Blue,        // 12
-                    Magenta,     // 13
-                    Cyan,        // 14
-                    White,       // 15
-                ]
-                .get(n as usize)
-                .copied()
-                .unwrap_or(Color::AnsiValue(n))
+                BASE_COLORS
+                    .get(n as usize)
+                    .copied()
+                    .unwrap_or(Color::AnsiValue(n))
             }
 
             // 24 bit colors: `2;<r>;<g>;<b>`
@@ -162,6 +164,88 @@ impl Color {
     }
 }
 
+impl Color {
+    /// Returns the RGB components of the color, if it can be represented as one.
+    ///
+    /// Named and ANSI colors are mapped using the default xterm palette, `Color::Reset` has no
+    /// RGB representation and returns `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zellij_widgets::prelude::*;
+    ///
+    /// assert_eq!(Color::Red.to_rgb(), Some((255, 0, 0)));
+    /// assert_eq!(Color::AnsiValue(16).to_rgb(), Some((0, 0, 0)));
+    /// assert_eq!(Color::Reset.to_rgb(), None);
+    /// ```
+    pub fn to_rgb(self) -> Option<(u8, u8, u8)> {
+        let rgb = match self {
+            Color::Reset => return None,
+            Color::Black => (0, 0, 0),
+            Color::DarkRed => (205, 0, 0),
+            Color::DarkGreen => (0, 205, 0),
+            Color::DarkYellow => (205, 205, 0),
+            Color::DarkBlue => (0, 0, 238),
+            Color::DarkMagenta => (205, 0, 205),
+            Color::DarkCyan => (0, 205, 205),
+            Color::Gray => (229, 229, 229),
+            Color::DarkGray => (127, 127, 127),
+            Color::Red => (255, 0, 0),
+            Color::Green => (0, 255, 0),
+            Color::Yellow => (255, 255, 0),
+            Color::Blue => (92, 92, 255),
+            Color::Magenta => (255, 0, 255),
+            Color::Cyan => (0, 255, 255),
+            Color::White => (255, 255, 255),
+            Color::Rgb { r, g, b } => (r, g, b),
+            Color::AnsiValue(n) => match n {
+                // The 16 base colors share the named colors palette
+                0..=15 => return BASE_COLORS[n as usize].to_rgb(),
+                // 6x6x6 color cube
+                16..=231 => {
+                    let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+                    let n = n - 16;
+                    (level(n / 36), level((n / 6) % 6), level(n % 6))
+                }
+                // Grayscale ramp
+                232..=255 => {
+                    let v = 8 + (n - 232) * 10;
+                    (v, v, v)
+                }
+            },
+        };
+        Some(rgb)
+    }
+
+    /// Linearly interpolates between `self` and `other`, returning an RGB color.
+    ///
+    /// `t` is clamped to `0.0..=1.0`, where `0.0` yields `self` and `1.0` yields `other`. If
+    /// either color has no RGB representation (see [`Color::to_rgb`]), `self` is returned
+    /// unchanged.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use zellij_widgets::prelude::*;
+    ///
+    /// let color = Color::Rgb { r: 200, g: 100, b: 0 }.lerp(Color::Black, 0.5);
+    /// assert_eq!(color, Color::Rgb { r: 100, g: 50, b: 0 });
+    /// ```
+    pub fn lerp(self, other: Color, t: f32) -> Color {
+        let (Some(from), Some(to)) = (self.to_rgb(), other.to_rgb()) else {
+            return self;
+        };
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+        Color::Rgb {
+            r: mix(from.0, to.0),
+            g: mix(from.1, to.1),
+            b: mix(from.2, to.2),
+        }
+    }
+}
+
 impl TryFrom<&str> for Color {
     type Error = ();
 
@@ -292,4 +376,34 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_to_rgb() {
+        assert_eq!(Color::Reset.to_rgb(), None);
+        assert_eq!(Color::Black.to_rgb(), Some((0, 0, 0)));
+        assert_eq!(Color::AnsiValue(9).to_rgb(), Color::Red.to_rgb());
+        assert_eq!(Color::AnsiValue(196).to_rgb(), Some((255, 0, 0)));
+        assert_eq!(Color::AnsiValue(232).to_rgb(), Some((8, 8, 8)));
+        assert_eq!(Color::AnsiValue(255).to_rgb(), Some((238, 238, 238)));
+    }
+
+    #[test]
+    fn test_lerp() {
+        let red = Color::Rgb { r: 255, g: 0, b: 0 };
+        assert_eq!(red.lerp(Color::Black, 0.0), red);
+        assert_eq!(red.lerp(Color::Black, 1.0), Color::Rgb { r: 0, g: 0, b: 0 });
+        assert_eq!(
+            red.lerp(Color::White, 0.5),
+            Color::Rgb {
+                r: 255,
+                g: 128,
+                b: 128
+            }
+        );
+        // Out of range amounts are clamped
+        assert_eq!(red.lerp(Color::Black, 2.0), Color::Rgb { r: 0, g: 0, b: 0 });
+        // Colors without RGB representation are left untouched
+        assert_eq!(Color::Reset.lerp(Color::Black, 0.5), Color::Reset);
+        assert_eq!(red.lerp(Color::Reset, 0.5), red);
+    }
 }
@@ -37,6 +37,7 @@ use crate::prelude::*;
 /// These methods are fluent setters. They return a `Line` with the property set.
 ///
 /// - [`Line::spans`] sets the content of the line.
+/// - [`Line::style`] sets the style of the line.
 /// - [`Line::alignment`] sets the alignment of the line.
 ///
 /// # Other Methods
@@ -63,6 +64,8 @@ use crate::prelude::*;
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
 pub struct Line<'a> {
     pub spans: Vec<Span<'a>>,
+    /// The style of the line, applied under the style of each span.
+    pub style: Style,
     pub alignment: Option<Alignment>,
 }
 
@@ -86,6 +89,7 @@ impl<'a> Line<'a> {
                 .lines()
                 .map(|v| Span::raw(v.to_string()))
                 .collect(),
+            style: Style::default(),
             alignment: None,
         }
     }
@@ -127,8 +131,8 @@ impl<'a> Line<'a> {
 
     /// Returns an iterator over the graphemes held by this line.
     ///
-    /// `base_style` is the [`Style`] that will be patched with each grapheme [`Style`] to get
-    /// the resulting [`Style`].
+    /// `base_style` is the [`Style`] that will be patched with the line [`Style`], then with each
+    /// grapheme [`Style`] to get the resulting [`Style`].
     ///
     /// ## Examples
     ///
@@ -152,12 +156,14 @@ impl<'a> Line<'a> {
         &'a self,
         base_style: Style,
     ) -> impl Iterator<Item = StyledGrapheme<'a>> {
+        let style = base_style.patch(self.style);
         self.spans
             .iter()
-            .flat_map(move |span| span.styled_graphemes(base_style))
+            .flat_map(move |span| span.styled_graphemes(style))
     }
 
-    /// Patches the style of each Span in an existing Line, adding modifiers from the given style.
+    /// Patches the style of the Line and of each of its Spans, adding modifiers from the given
+    /// style.
     ///
     /// ## Examples
     ///
@@ -177,15 +183,16 @@ impl<'a> Line<'a> {
     /// assert_ne!(raw_line, styled_line);
     ///
     /// raw_line.patch_style(style);
-    /// assert_eq!(raw_line, styled_line);
+    /// assert_eq!(raw_line, styled_line.style(style));
     /// ```
     pub fn patch_style(&mut self, style: Style) {
+        self.style = self.style.patch(style);
         for span in &mut self.spans {
             span.patch_style(style);
         }
     }
 
-    /// Resets the style of each Span in the Line.
+    /// Resets the style of the Line and of each of its Spans.
     /// Equivalent to calling `patch_style(Style::reset())`.
     ///
     /// ## Examples
@@ -196,18 +203,40 @@ impl<'a> Line<'a> {
     /// let mut line = Line::from(vec![
     ///     Span::styled("My", Style::default().fg(Color::Yellow)),
     ///     Span::styled(" text", Style::default().add_modifier(Modifier::BOLD)),
-    /// ]);
+    /// ])
+    /// .style(Style::default().fg(Color::Red));
     ///
     /// line.reset_style();
+    /// assert_eq!(Style::reset(), line.style);
     /// assert_eq!(Style::reset(), line.spans[0].style);
     /// assert_eq!(Style::reset(), line.spans[1].style);
     /// ```
     pub fn reset_style(&mut self) {
+        self.style = Style::reset();
         for span in &mut self.spans {
             span.reset_style();
         }
     }
 
+    /// Sets the style of this line of text.
+    ///
+    /// The line style is applied under the style of each span, so spans only override the
+    /// properties they set.
+    ///
+    /// ## Examples
+    ///
+    /// ```rust
+    /// # use zellij_widgets::prelude::*;
+    ///
+    /// let line = Line::from(vec![Span::raw("My"), Span::styled(" text", Style::new().red())])
+    ///     .style(Style::new().blue().on_white());
+    /// assert_eq!(Style::new().blue().on_white(), line.style);
+    /// ```
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
     /// Sets the target alignment for this line of text.
     /// Defaults to: [`None`], meaning the alignment is determined by the rendering widget.
     ///
@@ -228,6 +257,18 @@ impl<'a> Line<'a> {
     }
 }
 
+impl<'a> Styled for Line<'a> {
+    type Item = Line<'a>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
 impl<'a> From<String> for Line<'a> {
     fn from(s: String) -> Self {
         Self::from(vec![Span::from(s)])
@@ -304,7 +345,16 @@ mod tests {
         assert_ne!(raw_line, styled_line);
 
         raw_line.patch_style(style);
-        assert_eq!(raw_line, styled_line);
+        assert_eq!(raw_line, styled_line.style(style));
+    }
+
+    #[test]
+    fn test_patch_style_line_style() {
+        let mut line = Line::from("My text").style(Style::default().fg(Color::Red));
+
+        line.patch_style(Style::default().bg(Color::White));
+        assert_eq!(Style::default().fg(Color::Red).bg(Color::White), line.style);
+        assert_eq!(Style::default().bg(Color::White), line.spans[0].style);
     }
 
     #[test]
@@ -312,9 +362,11 @@ mod tests {
         let mut line = Line::from(vec![
             Span::styled("My", Style::default().fg(Color::Yellow)),
             Span::styled(" text", Style::default().add_modifier(Modifier::BOLD)),
-        ]);
+        ])
+        .style(Style::default().fg(Color::Red));
 
         line.reset_style();
+        assert_eq!(Style::reset(), line.style);
         assert_eq!(Style::reset(), line.spans[0].style);
         assert_eq!(Style::reset(), line.spans[1].style);
     }
@@ -406,6 +458,25 @@ mod tests {
         );
     }
 
+    #[test]
+    fn styled_graphemes_line_style() {
+        const RED: Style = Style::new().fg(Color::Red);
+        const BLUE_ON_WHITE: Style = Style::new().fg(Color::Blue).bg(Color::White);
+        const RED_ON_WHITE: Style = Style::new().fg(Color::Red).bg(Color::White);
+
+        let line = Line::from(vec![Span::raw("a"), Span::styled("b", RED)]).style(BLUE_ON_WHITE);
+        let styled_graphemes = line
+            .styled_graphemes(Style::default())
+            .collect::<Vec<StyledGrapheme>>();
+        assert_eq!(
+            styled_graphemes,
+            vec![
+                StyledGrapheme::new("a", BLUE_ON_WHITE),
+                StyledGrapheme::new("b", RED_ON_WHITE),
+            ],
+        );
+    }
+
     #[test]
     fn raw_str() {
         let line = Line::raw("test content");
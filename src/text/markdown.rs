@@ -0,0 +1,245 @@
+//! A minimal, hand-rolled markdown parser producing styled [`Text`].
+use crate::style::{Color, Modifier, Style};
+
+use super::{Line, Span, Text};
+
+const BOLD_STYLE: Style = Style::new().add_modifier(Modifier::BOLD);
+const ITALIC_STYLE: Style = Style::new().add_modifier(Modifier::ITALIC);
+const CODE_STYLE: Style = Style::new().fg(Color::Yellow);
+const HEADING_STYLE: Style = Style::new().add_modifier(Modifier::BOLD);
+const TOP_HEADING_STYLE: Style =
+    Style::new().add_modifier(Modifier::BOLD.union(Modifier::UNDERLINED));
+const BULLET: &str = "• ";
+
+impl<'a> Text<'a> {
+    /// Create a styled text from a minimal subset of markdown.
+    ///
+    /// Each input line produces one [`Line`]. The supported syntax is:
+    ///
+    /// - `**bold**` is rendered [`Modifier::BOLD`]
+    /// - `*italic*` is rendered [`Modifier::ITALIC`]
+    /// - `` `code` `` is rendered in yellow, its content is not parsed any further
+    /// - `# heading` (up to `######`) is rendered bold, level one headings are also underlined
+    /// - `- item`, `* item` and `+ item` bullets are rendered with a `•`, keeping their indentation
+    ///
+    /// Markers without a closing counterpart are kept as plain text.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use zellij_widgets::prelude::*;
+    /// let text = Text::from_markdown("# Help\n- press `q` to **quit**");
+    /// assert_eq!(text.height(), 2);
+    /// assert_eq!(String::from(text.lines[1].clone()), "• press q to quit");
+    /// ```
+    pub fn from_markdown(s: &str) -> Text<'a> {
+        s.lines().map(parse_line).collect::<Vec<_>>().into()
+    }
+}
+
+/// Parse a single markdown line, handling block level syntax (headings and bullets).
+fn parse_line<'a>(line: &str) -> Line<'a> {
+    if let Some((level, content)) = heading(line) {
+        let style = if level == 1 {
+            TOP_HEADING_STYLE
+        } else {
+            HEADING_STYLE
+        };
+        let mut line = Line::from(parse_inline(content));
+        line.patch_style(style);
+        return line;
+    }
+
+    let trimmed = line.trim_start();
+    let indent = &line[..line.len() - trimmed.len()];
+    if let Some(content) = ["- ", "* ", "+ "]
+        .iter()
+        .find_map(|marker| trimmed.strip_prefix(marker))
+    {
+        let mut spans = vec![Span::raw(format!("{indent}{BULLET}"))];
+        spans.extend(parse_inline(content));
+        return Line::from(spans);
+    }
+
+    Line::from(parse_inline(line))
+}
+
+/// Returns the heading level and content if the line is a heading, e.g. `## Title`.
+fn heading(line: &str) -> Option<(usize, &str)> {
+    let level = line.chars().take_while(|&c| c == '#').count();
+    if !(1..=6).contains(&level) {
+        return None;
+    }
+    line[level..]
+        .strip_prefix(' ')
+        .map(|content| (level, content))
+}
+
+/// Returns true if an emphasis marker followed by `after` can open, i.e. it is directly followed by
+/// some text rather than whitespace, so `2 * 3` stays literal.
+fn opens(after: &str) -> bool {
+    after.chars().next().is_some_and(|c| !c.is_whitespace())
+}
+
+/// Returns true if `s` contains a single `*` that can close an italic, skipping the `*` pairs of
+/// bold markers.
+fn has_single_star(s: &str) -> bool {
+    let mut rest = s;
+    while let Some(i) = rest.find('*') {
+        match rest[i + 1..].strip_prefix('*') {
+            Some(after) => rest = after,
+            None => return true,
+        }
+    }
+    false
+}
+
+/// Parse the inline syntax of a line (bold, italic and code) into styled spans.
+fn parse_inline<'a>(s: &str) -> Vec<Span<'a>> {
+    let mut spans = Vec::new();
+    let mut current = String::new();
+    let mut bold = false;
+    let mut italic = false;
+
+    let style = |bold: bool, italic: bool| {
+        let mut style = Style::default();
+        if bold {
+            style = style.patch(BOLD_STYLE);
+        }
+        if italic {
+            style = style.patch(ITALIC_STYLE);
+        }
+        style
+    };
+    let flush = |spans: &mut Vec<Span<'a>>, current: &mut String, style: Style| {
+        if !current.is_empty() {
+            spans.push(Span::styled(std::mem::take(current), style));
+        }
+    };
+
+    let mut rest = s;
+    while let Some(c) = rest.chars().next() {
+        if let Some(after) = rest.strip_prefix('`') {
+            if let Some(end) = after.find('`') {
+                flush(&mut spans, &mut current, style(bold, italic));
+                spans.push(Span::styled(after[..end].to_string(), CODE_STYLE));
+                rest = &after[end + 1..];
+                continue;
+            }
+        } else if let Some(after) = rest.strip_prefix("**") {
+            if bold || (opens(after) && after.contains("**")) {
+                flush(&mut spans, &mut current, style(bold, italic));
+                bold = !bold;
+                rest = after;
+                continue;
+            }
+        } else if let Some(after) = rest.strip_prefix('*') {
+            if italic || (opens(after) && !after.starts_with('*') && has_single_star(after)) {
+                flush(&mut spans, &mut current, style(bold, italic));
+                italic = !italic;
+                rest = after;
+                continue;
+            }
+        }
+        current.push(c);
+        rest = &rest[c.len_utf8()..];
+    }
+    flush(&mut spans, &mut current, style(bold, italic));
+
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inline_styles() {
+        let text = Text::from_markdown("a **bold**, *italic* and `code` word");
+        assert_eq!(
+            text.lines[0].spans,
+            vec![
+                Span::raw("a "),
+                Span::styled("bold", BOLD_STYLE),
+                Span::raw(", "),
+                Span::styled("italic", ITALIC_STYLE),
+                Span::raw(" and "),
+                Span::styled("code", CODE_STYLE),
+                Span::raw(" word"),
+            ]
+        );
+    }
+
+    #[test]
+    fn nested_bold_italic() {
+        let text = Text::from_markdown("**bold *both***");
+        assert_eq!(
+            text.lines[0].spans,
+            vec![
+                Span::styled("bold ", BOLD_STYLE),
+                Span::styled("both", BOLD_STYLE.patch(ITALIC_STYLE)),
+            ]
+        );
+    }
+
+    #[test]
+    fn code_is_not_parsed() {
+        let text = Text::from_markdown("`**not bold**`");
+        assert_eq!(
+            text.lines[0].spans,
+            vec![Span::styled("**not bold**", CODE_STYLE)]
+        );
+    }
+
+    #[test]
+    fn unclosed_markers_are_literal() {
+        let text = Text::from_markdown("2 * 3 = `six and **more");
+        assert_eq!(
+            text.lines[0].spans,
+            vec![Span::raw("2 * 3 = `six and **more")]
+        );
+    }
+
+    #[test]
+    fn unclosed_italic_next_to_bold() {
+        let text = Text::from_markdown("*a **b**");
+        assert_eq!(
+            text.lines[0].spans,
+            vec![Span::raw("*a "), Span::styled("b", BOLD_STYLE)]
+        );
+
+        let text = Text::from_markdown("**a *b**");
+        assert_eq!(text.lines[0].spans, vec![Span::styled("a *b", BOLD_STYLE)]);
+    }
+
+    #[test]
+    fn headings() {
+        let text = Text::from_markdown("# Title\n### Sub *title*\n#no heading");
+        assert_eq!(
+            text.lines[0].spans,
+            vec![Span::styled("Title", TOP_HEADING_STYLE)]
+        );
+        assert_eq!(
+            text.lines[1].spans,
+            vec![
+                Span::styled("Sub ", HEADING_STYLE),
+                Span::styled("title", HEADING_STYLE.patch(ITALIC_STYLE)),
+            ]
+        );
+        assert_eq!(text.lines[2].spans, vec![Span::raw("#no heading")]);
+    }
+
+    #[test]
+    fn bullets() {
+        let text = Text::from_markdown("- first\n  * **second**\nnot-a-bullet");
+        assert_eq!(
+            text.lines[0].spans,
+            vec![Span::raw("• "), Span::raw("first")]
+        );
+        assert_eq!(
+            text.lines[1].spans,
+            vec![Span::raw("  • "), Span::styled("second", BOLD_STYLE)]
+        );
+        assert_eq!(text.lines[2].spans, vec![Span::raw("not-a-bullet")]);
+    }
+}
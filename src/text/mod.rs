@@ -1,6 +1,7 @@
 //! A module for working with text, lines, spans, and graphemes.
 pub mod grapheme;
 pub mod line;
+mod markdown;
 pub mod masked;
 pub mod span;
 
@@ -115,6 +116,8 @@ impl<'a> Text<'a> {
 
     /// Patches the style of each line in an existing Text, adding modifiers from the given style.
     ///
+    /// See [`Line::patch_style`], both the line style and the style of its spans are patched.
+    ///
     /// # Examples
     ///
     /// ```rust
@@ -147,6 +150,7 @@ impl<'a> Text<'a> {
     ///
     /// text.reset_style();
     /// for line in &text.lines {
+    ///     assert_eq!(Style::reset(), line.style);
     ///     for span in &line.spans {
     ///         assert_eq!(Style::reset(), span.style);
     ///     }
@@ -237,8 +241,8 @@ mod tests {
         assert_eq!(
             text.lines,
             vec![
-                Line::from(Span::styled("The first line", style)),
-                Line::from(Span::styled("The second line", style))
+                Line::from(Span::styled("The first line", style)).style(style),
+                Line::from(Span::styled("The second line", style)).style(style)
             ]
         );
     }
@@ -266,8 +270,8 @@ mod tests {
         assert_eq!(
             text.lines,
             vec![
-                Line::from(Span::styled("The first line", expected_style)),
-                Line::from(Span::styled("The second line", expected_style))
+                Line::from(Span::styled("The first line", expected_style)).style(expected_style),
+                Line::from(Span::styled("The second line", expected_style)).style(expected_style)
             ]
         );
     }
@@ -281,8 +285,8 @@ mod tests {
         assert_eq!(
             text.lines,
             vec![
-                Line::from(Span::styled("The first line", Style::reset())),
-                Line::from(Span::styled("The second line", Style::reset()))
+                Line::from(Span::styled("The first line", Style::reset())).style(Style::reset()),
+                Line::from(Span::styled("The second line", Style::reset())).style(Style::reset())
             ]
         );
     }
@@ -0,0 +1,274 @@
+use unicode_width::UnicodeWidthStr;
+
+use crate::prelude::*;
+
+pub use state::AccordionState;
+
+mod state;
+
+const EXPANDED_SYMBOL: &str = "▾ ";
+const COLLAPSED_SYMBOL: &str = "▸ ";
+const DEFAULT_HIGHLIGHT_STYLE: Style = Style::new().add_modifier(Modifier::REVERSED);
+
+/// A single section of an [`Accordion`], made of a one line header and a body.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct AccordionSection<'a> {
+    /// The header line, always rendered
+    pub header: Line<'a>,
+    /// The body text, only rendered when the section is expanded
+    pub body: Text<'a>,
+}
+
+impl<'a> AccordionSection<'a> {
+    /// Create a new section from a header and a body.
+    pub fn new<H, B>(header: H, body: B) -> Self
+    where
+        H: Into<Line<'a>>,
+        B: Into<Text<'a>>,
+    {
+        Self {
+            header: header.into(),
+            body: body.into(),
+        }
+    }
+}
+
+/// A widget to display stacked collapsible sections
+///
+/// Every section header is always rendered, prefixed with an expanded (`▾`) or collapsed (`▸`)
+/// marker. The body of a section is rendered right under its header only when the section is
+/// expanded in the [`AccordionState`], pushing the following sections down.
+///
+/// ```text
+/// ▸ General
+/// ▾ Appearance
+///   Theme: dark
+///   Font size: 12
+/// ▸ Keybindings
+/// ```
+///
+/// # Example
+///
+/// ```rust
+/// use zellij_widgets::prelude::*;
+///
+/// let accordion = Accordion::new(vec![
+///     AccordionSection::new("General", "Language: en"),
+///     AccordionSection::new("Appearance", "Theme: dark\nFont size: 12"),
+/// ])
+/// .header_style(Style::default().bold());
+///
+/// let mut state = AccordionState::new(2);
+/// state.toggle(1);
+/// ```
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct Accordion<'a> {
+    /// The optional block of the accordion.
+    block: Option<Block<'a>>,
+    /// The sections of the accordion.
+    sections: Vec<AccordionSection<'a>>,
+    /// The style of the whole widget.
+    style: Style,
+    /// The style of the section headers.
+    header_style: Style,
+    /// The style of the selected section header.
+    highlight_style: Style,
+}
+
+impl<'a> Accordion<'a> {
+    /// Create a new accordion with the given sections.
+    pub fn new(sections: Vec<AccordionSection<'a>>) -> Self {
+        Self {
+            block: None,
+            sections,
+            style: Style::default(),
+            header_style: Style::default(),
+            highlight_style: DEFAULT_HIGHLIGHT_STYLE,
+        }
+    }
+
+    /// Set the block of the accordion.
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Set the style of the accordion.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the style of the section headers.
+    pub fn header_style(mut self, style: Style) -> Self {
+        self.header_style = style;
+        self
+    }
+
+    /// Set the style of the selected section header.
+    pub fn highlight_style(mut self, style: Style) -> Self {
+        self.highlight_style = style;
+        self
+    }
+}
+
+impl<'a> Styled for Accordion<'a> {
+    type Item = Accordion<'a>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl<'a> StateWidget for Accordion<'a> {
+    type State = AccordionState;
+
+    fn render(self, area: Geometry, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+
+        let accordion_area = match self.block {
+            Some(block) => {
+                let inner_area = block.inner(area);
+                block.render(area, buf);
+                inner_area
+            }
+            None => area,
+        };
+
+        if accordion_area.is_empty() {
+            return;
+        }
+
+        let x = accordion_area.left();
+        let cols = accordion_area.cols;
+        let body_indent = (COLLAPSED_SYMBOL.width() as u16).min(cols);
+        let mut y = accordion_area.top();
+
+        for (idx, section) in self.sections.iter().enumerate() {
+            if y >= accordion_area.bottom() {
+                break;
+            }
+
+            // Header
+            let expanded = state.is_expanded(idx);
+            let symbol = if expanded {
+                EXPANDED_SYMBOL
+            } else {
+                COLLAPSED_SYMBOL
+            };
+            let header_area = Geometry {
+                x,
+                y,
+                cols,
+                rows: 1,
+            };
+            buf.set_style(header_area, self.header_style);
+            let pos = buf.set_stringn(x, y, symbol, cols as usize, self.header_style);
+            buf.set_line(pos.0, y, &section.header, cols.saturating_sub(pos.0 - x));
+            if idx == state.selected {
+                buf.set_style(header_area, self.highlight_style);
+            }
+            y += 1;
+
+            // Body
+            if !expanded {
+                continue;
+            }
+            for line in &section.body.lines {
+                if y >= accordion_area.bottom() {
+                    break;
+                }
+                buf.set_line(x + body_indent, y, line, cols - body_indent);
+                y += 1;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_buffer_eq;
+
+    fn accordion() -> Accordion<'static> {
+        Accordion::new(vec![
+            AccordionSection::new("One", "Body 1\nMore 1"),
+            AccordionSection::new("Two", "Body 2"),
+        ])
+    }
+
+    fn header_row(y: u16) -> Geometry {
+        Geometry {
+            x: 0,
+            y,
+            rows: 1,
+            cols: 10,
+        }
+    }
+
+    #[test]
+    fn accordion_collapsed_renders_headers_only() {
+        let area = Geometry::new(4, 10);
+        let mut buffer = Buffer::empty(area);
+        let mut state = AccordionState::new(2);
+        accordion().render(area, &mut buffer, &mut state);
+
+        let mut expected =
+            Buffer::with_lines(vec!["▸ One     ", "▸ Two     ", "          ", "          "]);
+        expected.set_style(header_row(0), Style::new().add_modifier(Modifier::REVERSED));
+        assert_buffer_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn accordion_toggle_shows_and_hides_body() {
+        let area = Geometry::new(4, 10);
+        let mut state = AccordionState::new(2);
+
+        state.toggle(0);
+        let mut buffer = Buffer::empty(area);
+        accordion().render(area, &mut buffer, &mut state);
+        // The following section is pushed down by the expanded body
+        let mut expected =
+            Buffer::with_lines(vec!["▾ One     ", "  Body 1  ", "  More 1  ", "▸ Two     "]);
+        expected.set_style(header_row(0), Style::new().add_modifier(Modifier::REVERSED));
+        assert_buffer_eq!(buffer, expected);
+
+        state.toggle(0);
+        let mut buffer = Buffer::empty(area);
+        accordion().render(area, &mut buffer, &mut state);
+        let mut expected =
+            Buffer::with_lines(vec!["▸ One     ", "▸ Two     ", "          ", "          "]);
+        expected.set_style(header_row(0), Style::new().add_modifier(Modifier::REVERSED));
+        assert_buffer_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn accordion_body_clipped_to_area() {
+        let area = Geometry::new(2, 10);
+        let mut buffer = Buffer::empty(area);
+        let mut state = AccordionState::new(2);
+        state.toggle(0);
+        accordion().render(area, &mut buffer, &mut state);
+
+        let mut expected = Buffer::with_lines(vec!["▾ One     ", "  Body 1  "]);
+        expected.set_style(header_row(0), Style::new().add_modifier(Modifier::REVERSED));
+        assert_buffer_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn accordion_highlights_selected_header() {
+        let area = Geometry::new(3, 10);
+        let mut buffer = Buffer::empty(area);
+        let mut state = AccordionState::new(2);
+        state.next();
+        accordion().render(area, &mut buffer, &mut state);
+
+        let mut expected = Buffer::with_lines(vec!["▸ One     ", "▸ Two     ", "          "]);
+        expected.set_style(header_row(1), Style::new().add_modifier(Modifier::REVERSED));
+        assert_buffer_eq!(buffer, expected);
+    }
+}
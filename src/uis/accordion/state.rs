@@ -0,0 +1,124 @@
+/// A state for the [`Accordion`](super::Accordion) widget.
+///
+/// It tracks which sections are expanded and which section header is currently selected.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct AccordionState {
+    /// The index of the selected section header
+    pub selected: usize,
+    /// Whether each section is expanded, indexed by section position
+    expanded: Vec<bool>,
+}
+
+impl AccordionState {
+    /// Create a new state with the given number of sections, all collapsed.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use zellij_widgets::prelude::*;
+    /// let state = AccordionState::new(3);
+    /// assert!(!state.is_expanded(0));
+    /// ```
+    pub fn new(len: usize) -> Self {
+        Self {
+            selected: 0,
+            expanded: vec![false; len],
+        }
+    }
+
+    /// Returns the number of sections tracked by the state.
+    pub fn len(&self) -> usize {
+        self.expanded.len()
+    }
+
+    /// Returns true if the state tracks no sections.
+    pub fn is_empty(&self) -> bool {
+        self.expanded.is_empty()
+    }
+
+    /// Returns true if the section at `index` is expanded.
+    pub fn is_expanded(&self, index: usize) -> bool {
+        self.expanded.get(index).copied().unwrap_or(false)
+    }
+
+    /// Expand the section at `index`, out of range indexes are ignored.
+    pub fn expand(&mut self, index: usize) {
+        if let Some(expanded) = self.expanded.get_mut(index) {
+            *expanded = true;
+        }
+    }
+
+    /// Collapse the section at `index`, out of range indexes are ignored.
+    pub fn collapse(&mut self, index: usize) {
+        if let Some(expanded) = self.expanded.get_mut(index) {
+            *expanded = false;
+        }
+    }
+
+    /// Toggle the section at `index` between expanded and collapsed.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use zellij_widgets::prelude::*;
+    /// let mut state = AccordionState::new(3);
+    /// state.toggle(1);
+    /// assert!(state.is_expanded(1));
+    /// state.toggle(1);
+    /// assert!(!state.is_expanded(1));
+    /// ```
+    pub fn toggle(&mut self, index: usize) {
+        if let Some(expanded) = self.expanded.get_mut(index) {
+            *expanded = !*expanded;
+        }
+    }
+
+    /// Toggle the currently selected section.
+    pub fn toggle_selected(&mut self) {
+        self.toggle(self.selected);
+    }
+
+    /// Select the next section header where the index is wrapped around.
+    pub fn next(&mut self) {
+        if !self.is_empty() {
+            self.selected = (self.selected + 1) % self.len();
+        }
+    }
+
+    /// Select the previous section header where the index is wrapped around.
+    pub fn previous(&mut self) {
+        if !self.is_empty() {
+            self.selected = (self.selected + self.len() - 1) % self.len();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn accordion_state_toggle() {
+        let mut state = AccordionState::new(2);
+        assert!(!state.is_expanded(0));
+
+        state.toggle(0);
+        assert!(state.is_expanded(0));
+        assert!(!state.is_expanded(1));
+
+        // Out of range indexes are ignored
+        state.toggle(5);
+        assert!(!state.is_expanded(5));
+    }
+
+    #[test]
+    fn accordion_state_navigation() {
+        let mut state = AccordionState::new(3);
+        state.previous();
+        assert_eq!(state.selected, 2);
+        state.next();
+        assert_eq!(state.selected, 0);
+
+        state.next();
+        state.toggle_selected();
+        assert!(state.is_expanded(1));
+    }
+}
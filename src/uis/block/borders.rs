@@ -3,6 +3,18 @@ use std::fmt::{self, Debug};
 
 bitflags! {
     /// Bitflags that can be composed to set the visible borders essentially on the block widget.
+    ///
+    /// The methods generated by `bitflags` are handy when configuring a block, e.g.
+    /// [`Borders::complement`] gives "all borders except these" (within [`Borders::ALL`]), and
+    /// [`Borders::iter`] yields each individual side that is set.
+    ///
+    /// ```rust
+    /// # use zellij_widgets::prelude::*;
+    /// assert_eq!(Borders::TOP.complement(), Borders::RIGHT | Borders::BOTTOM | Borders::LEFT);
+    ///
+    /// let sides: Vec<Borders> = (Borders::TOP | Borders::LEFT).iter().collect();
+    /// assert_eq!(sides, vec![Borders::TOP, Borders::LEFT]);
+    /// ```
     #[derive(Default, Clone, Copy, Eq, PartialEq, Hash)]
     pub struct Borders: u8 {
         /// Show no border (default)
@@ -87,4 +99,34 @@ mod tests {
         assert_eq!(format!("{:?}", top_right), "TOP | RIGHT");
         assert_eq!(format!("{:?}", top_bottom_left), "TOP | BOTTOM | LEFT");
     }
+
+    #[test]
+    fn test_complement() {
+        assert_eq!(
+            Borders::TOP.complement(),
+            Borders::RIGHT | Borders::BOTTOM | Borders::LEFT
+        );
+        assert_eq!(
+            (Borders::LEFT | Borders::RIGHT).complement(),
+            Borders::TOP | Borders::BOTTOM
+        );
+        assert_eq!(Borders::NONE.complement(), Borders::ALL);
+        assert_eq!(Borders::ALL.complement(), Borders::NONE);
+    }
+
+    #[test]
+    fn test_iter() {
+        let sides: Vec<Borders> = (Borders::TOP | Borders::BOTTOM | Borders::LEFT)
+            .iter()
+            .collect();
+        assert_eq!(sides, vec![Borders::TOP, Borders::BOTTOM, Borders::LEFT]);
+
+        let sides: Vec<Borders> = Borders::ALL.iter().collect();
+        assert_eq!(
+            sides,
+            vec![Borders::TOP, Borders::RIGHT, Borders::BOTTOM, Borders::LEFT]
+        );
+
+        assert_eq!(Borders::NONE.iter().count(), 0);
+    }
 }
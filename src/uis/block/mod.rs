@@ -510,9 +510,7 @@ impl<'a> Block<'a> {
 
                 // Clone the title's content, applying block title style then the title style
                 let mut content = title.content.clone();
-                for span in content.spans.iter_mut() {
-                    span.style = self.titles_style.patch(span.style);
-                }
+                content.style = self.titles_style.patch(content.style);
 
                 buf.set_line(
                     title_x + area.left(),
@@ -526,25 +524,26 @@ impl<'a> Block<'a> {
     fn render_center_titles(&self, position: Position, area: Geometry, buf: &mut Buffer) {
         let (_, _, title_area_cols) = self.calculate_title_area_offsets(area);
 
-        let titles = self
+        // Compute each title width once, it is needed for both the total and the offsets
+        let titles: Vec<(&Title, u16)> = self
             .titles
             .iter()
-            .filter(|title| self.title_filter(title, Alignment::Center, position));
+            .filter(|title| self.title_filter(title, Alignment::Center, position))
+            .map(|title| (title, title.content.width() as u16))
+            .collect();
 
         let titles_sum = titles
-            .clone()
-            .fold(-1, |acc, f| acc + f.content.width() as i16 + 1); // First element isn't spaced
+            .iter()
+            .fold(-1, |acc, (_, width)| acc + *width as i16 + 1); // First element isn't spaced
 
         let mut current_offset = area.cols.saturating_sub(titles_sum as u16) / 2;
-        titles.for_each(|title| {
+        titles.into_iter().for_each(|(title, width)| {
             let title_x = current_offset;
-            current_offset += title.content.width() as u16 + 1;
+            current_offset += width + 1;
 
             // Clone the title's content, applying block title style then the title style
             let mut content = title.content.clone();
-            for span in content.spans.iter_mut() {
-                span.style = self.titles_style.patch(span.style);
-            }
+            content.style = self.titles_style.patch(content.style);
 
             buf.set_line(
                 title_x + area.left(),
@@ -569,9 +568,7 @@ impl<'a> Block<'a> {
 
                 // Clone the title's content, applying block title style then the title style
                 let mut content = title.content.clone();
-                for span in content.spans.iter_mut() {
-                    span.style = self.titles_style.patch(span.style);
-                }
+                content.style = self.titles_style.patch(content.style);
 
                 buf.set_line(
                     area.cols.saturating_sub(title_x) + area.left(),
@@ -621,6 +618,7 @@ impl<'a> Styled for Block<'a> {
 mod tests {
     use super::*;
     use crate::{
+        assert_buffer_eq,
         layout::Geometry,
         style::{Color, Modifier, Stylize},
         text::Line,
@@ -1071,4 +1069,22 @@ mod tests {
             .title_alignment(Alignment::Right);
         assert_eq!(block_right.titles_alignment, Alignment::Right);
     }
+
+    #[test]
+    fn render_center_titles() {
+        // Each centered title is measured once, wide graphemes included
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title_alignment(Alignment::Center)
+            .title("One")
+            .title("日本");
+        let area = Geometry::new(3, 12);
+        let mut buffer = Buffer::empty(area);
+        block.render(area, &mut buffer);
+
+        assert_buffer_eq!(
+            buffer,
+            Buffer::with_lines(vec!["┌─One─日本─┐", "│          │", "└──────────┘"])
+        );
+    }
 }
@@ -10,10 +10,14 @@ use crate::prelude::*;
 /// This [`Style`] will be combined with the [`Style`] of the inner [`Text`]. The [`Style`]
 /// of the [`Text`] will be added to the [`Style`] of the [`ListItem`].
 ///
+/// An item can be marked as disabled with [`ListItem::disabled`], disabled items are skipped by
+/// [`ListState`] navigation and rendered dimmed by [`List`].
+///
 #[derive(Default, Debug, Clone, Eq, PartialEq, Hash)]
 pub struct ListItem<'a> {
     pub field: Text<'a>,
     pub style: Style,
+    pub disabled: bool,
 }
 
 impl<'a> ListItem<'a> {
@@ -24,9 +28,28 @@ impl<'a> ListItem<'a> {
         ListItem {
             field: text.into(),
             style: Style::default(),
+            disabled: false,
         }
     }
 
+    /// Mark the item as disabled (non-selectable and dimmed)
+    ///
+    /// Example:
+    /// ```rust
+    /// # use zellij_widgets::prelude::*;
+    /// let item = ListItem::new("Item 1").disabled(true);
+    /// assert!(item.is_disabled());
+    /// ```
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// Returns true if the item is disabled
+    pub fn is_disabled(&self) -> bool {
+        self.disabled
+    }
+
     pub fn height(&self) -> usize {
         self.field.height()
     }
@@ -59,4 +82,13 @@ mod tests {
         assert_eq!(item.height(), 1);
         assert_eq!(item.width(), 6);
     }
+
+    #[test]
+    fn list_item_disabled() {
+        let item = ListItem::new("Item 1");
+        assert!(!item.is_disabled());
+
+        let item = item.disabled(true);
+        assert!(item.is_disabled());
+    }
 }
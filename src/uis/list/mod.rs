@@ -8,6 +8,8 @@ mod highlight_style;
 mod list_item;
 mod state;
 
+const DISABLED_STYLE: Style = Style::new().add_modifier(Modifier::DIM);
+
 /// A widget to display multiple items among which one can be selected
 ///
 /// A list is a collection of [`ListItem`]s.
@@ -44,6 +46,11 @@ impl<'a> List<'a> {
         }
     }
 
+    /// The items of the list, e.g. to pass to [`ListState::select_next`] before rendering
+    pub fn items(&self) -> &[ListItem<'a>] {
+        &self.items
+    }
+
     pub fn block(mut self, block: Block<'a>) -> Self {
         self.block = Some(block);
         self
@@ -165,41 +172,147 @@ impl<'a> StateWidget for List<'a> {
                     rows: item.height() as u16,
                 };
 
-                if let Some(index) = state.highlight_index() {
-                    if index == i {
-                        buf.set_style(item_gemo, self.highlight_style.style);
-                        for (j, line) in item.field.lines.iter().enumerate() {
-                            if j == 0 {
-                                let line_text: String = line.clone().into();
-                                let highlight_symbol =
-                                    format!("{} {}", self.highlight_style.symbol, line_text);
-                                buf.set_string(x, y, highlight_symbol, self.highlight_style.style);
-
-                                let pos = self.highlight_style.symbol.len() as u16;
-
-                                buf.set_line(
-                                    item_gemo.x + pos + 1,
-                                    item_gemo.y + j as u16,
-                                    line,
-                                    max_cols,
-                                );
-                            } else {
-                                let pos = self.highlight_style.symbol.len() as u16;
-                                buf.set_line(
-                                    item_gemo.x + pos,
-                                    item_gemo.y + j as u16,
-                                    line,
-                                    max_cols,
-                                );
-                            }
-                        }
-                    } else {
-                        buf.set_style(area, item.style);
-                        for (j, line) in item.field.lines.iter().enumerate() {
-                            buf.set_line(item_gemo.x, item_gemo.y + j as u16, line, max_cols);
+                if state.highlight_index() == Some(i) && !item.is_disabled() {
+                    buf.set_style(item_gemo, self.highlight_style.style);
+                    for (j, line) in item.field.lines.iter().enumerate() {
+                        if j == 0 {
+                            let line_text: String = line.clone().into();
+                            let highlight_symbol =
+                                format!("{} {}", self.highlight_style.symbol, line_text);
+                            buf.set_string(x, y, highlight_symbol, self.highlight_style.style);
+
+                            let pos = self.highlight_style.symbol.len() as u16;
+
+                            buf.set_line(
+                                item_gemo.x + pos + 1,
+                                item_gemo.y + j as u16,
+                                line,
+                                max_cols,
+                            );
+                        } else {
+                            let pos = self.highlight_style.symbol.len() as u16;
+                            buf.set_line(item_gemo.x + pos, item_gemo.y + j as u16, line, max_cols);
                         }
                     }
+                } else {
+                    buf.set_style(area, item.style);
+                    for (j, line) in item.field.lines.iter().enumerate() {
+                        buf.set_line(item_gemo.x, item_gemo.y + j as u16, line, max_cols);
+                    }
+                    if item.is_disabled() {
+                        buf.set_style(item_gemo, DISABLED_STYLE);
+                    }
                 }
             });
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_buffer_eq;
+
+    #[test]
+    fn render_disabled_items_dimmed() {
+        let items = vec![
+            ListItem::new("Item 0"),
+            ListItem::new("Item 1").disabled(true),
+            ListItem::new("Item 2"),
+        ];
+        let mut state = ListState::new(Some(0), 0);
+        state.select_next(&items);
+        assert_eq!(state.highlight_index(), Some(2));
+
+        let area = Geometry::new(5, 10);
+        let mut buf = Buffer::empty(area);
+        List::new_with_items(items).render(area, &mut buf, &mut state);
+
+        assert_eq!(buf.get(0, 0).symbol(), "I");
+        assert!(!buf.get(0, 0).modifier.contains(Modifier::DIM));
+
+        assert_eq!(buf.get(0, 1).symbol(), "I");
+        assert!(buf.get(0, 1).modifier.contains(Modifier::DIM));
+
+        // Highlighted item is prefixed with the highlight symbol
+        assert_eq!(buf.get(0, 2).symbol(), "-");
+        assert!(!buf.get(0, 2).modifier.contains(Modifier::DIM));
+    }
+
+    #[test]
+    fn render_disabled_item_never_highlighted() {
+        let items = vec![ListItem::new("Item 0").disabled(true)];
+        let mut state = ListState::new(Some(0), 0);
+
+        let area = Geometry::new(2, 10);
+        let mut buf = Buffer::empty(area);
+        List::new_with_items(items).render(area, &mut buf, &mut state);
+
+        assert_eq!(buf.get(0, 0).symbol(), "I");
+        assert!(buf.get(0, 0).modifier.contains(Modifier::DIM));
+    }
+
+    #[test]
+    fn render_disabled_highlighted_item_among_others() {
+        let list = List::new_with_items(vec![
+            ListItem::new("Item 0"),
+            ListItem::new("Item 1").disabled(true),
+            ListItem::new("Item 2"),
+        ]);
+        // set_highlight_index does not check disabled items, so the
+        // disabled item stays dimmed and no row is highlighted
+        let mut state = ListState::new(None, 0);
+        state.set_highlight_index(1);
+
+        let area = Geometry::new(4, 8);
+        let mut buffer = Buffer::empty(area);
+        list.render(area, &mut buffer, &mut state);
+
+        let mut expected = Buffer::with_lines(vec!["Item 0  ", "Item 1  ", "Item 2  ", "        "]);
+        expected.set_style(
+            Geometry {
+                x: 0,
+                y: 1,
+                rows: 1,
+                cols: 8,
+            },
+            DISABLED_STYLE,
+        );
+        assert_buffer_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn render_disabled_item_without_highlight() {
+        let list = List::new_with_items(vec![
+            ListItem::new("Item 0"),
+            ListItem::new("Item 1").disabled(true),
+        ]);
+        let mut state = ListState::new(None, 0);
+
+        let area = Geometry::new(3, 8);
+        let mut buffer = Buffer::empty(area);
+        list.render(area, &mut buffer, &mut state);
+
+        let mut expected = Buffer::with_lines(vec!["Item 0  ", "Item 1  ", "        "]);
+        expected.set_style(
+            Geometry {
+                x: 0,
+                y: 1,
+                rows: 1,
+                cols: 8,
+            },
+            DISABLED_STYLE,
+        );
+        assert_buffer_eq!(buffer, expected);
+    }
+
+    #[test]
+    fn select_next_through_list_items() {
+        let list = List::new_with_items(vec![
+            ListItem::new("Item 0").disabled(true),
+            ListItem::new("Item 1"),
+        ]);
+        let mut state = ListState::default();
+        state.select_next(list.items());
+        assert_eq!(state.highlight_index(), Some(1));
+    }
+}
@@ -1,3 +1,5 @@
+use super::ListItem;
+
 #[derive(Debug, Default, PartialEq, Hash)]
 pub struct ListState {
     /// The index of the currently selected / highlighted item index
@@ -28,6 +30,11 @@ impl ListState {
 
     /// Set the index of the currently selected / highlighted item index
     ///
+    /// This does not check whether the item is disabled; a disabled item is never drawn
+    /// highlighted, so the list then renders without a highlight. Use [`ListState::select`]
+    /// or [`ListState::select_next`] with [`List::items`](crate::prelude::List::items) to
+    /// respect disabled items.
+    ///
     /// Example:
     /// ```rust
     /// # use zellij_widgets::prelude::*;
@@ -75,4 +82,134 @@ impl ListState {
     pub fn start_position(&self) -> usize {
         self.start_pos_to_display
     }
+
+    /// Select the item at `index`, this is a no-op if the item is disabled or out of range
+    ///
+    /// Example:
+    /// ```rust
+    /// # use zellij_widgets::prelude::*;
+    /// let items = vec![ListItem::new("Item 1"), ListItem::new("Item 2").disabled(true)];
+    /// let mut list_state = ListState::new(Some(0), 0);
+    /// list_state.select(1, &items);
+    /// assert_eq!(list_state.highlight_index(), Some(0));
+    /// ```
+    pub fn select(&mut self, index: usize, items: &[ListItem]) {
+        if items.get(index).is_some_and(|item| !item.is_disabled()) {
+            self.highlight_index = Some(index);
+        }
+    }
+
+    /// Move the highlight to the next enabled item, skipping disabled ones
+    ///
+    /// The highlight stays unchanged if there is no enabled item after the current one. If nothing
+    /// is highlighted yet, the first enabled item is selected.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use zellij_widgets::prelude::*;
+    /// let items = vec![
+    ///     ListItem::new("Item 1"),
+    ///     ListItem::new("Item 2").disabled(true),
+    ///     ListItem::new("Item 3"),
+    /// ];
+    /// let mut list_state = ListState::new(Some(0), 0);
+    /// list_state.select_next(&items);
+    /// assert_eq!(list_state.highlight_index(), Some(2));
+    /// ```
+    pub fn select_next(&mut self, items: &[ListItem]) {
+        let start = self.highlight_index.map_or(0, |i| i + 1);
+        if let Some(index) = (start..items.len()).find(|&i| !items[i].is_disabled()) {
+            self.highlight_index = Some(index);
+        }
+    }
+
+    /// Move the highlight to the previous enabled item, skipping disabled ones
+    ///
+    /// The highlight stays unchanged if there is no enabled item before the current one. If nothing
+    /// is highlighted yet, the first enabled item is selected.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use zellij_widgets::prelude::*;
+    /// let items = vec![
+    ///     ListItem::new("Item 1"),
+    ///     ListItem::new("Item 2").disabled(true),
+    ///     ListItem::new("Item 3"),
+    /// ];
+    /// let mut list_state = ListState::new(Some(2), 0);
+    /// list_state.select_previous(&items);
+    /// assert_eq!(list_state.highlight_index(), Some(0));
+    /// ```
+    pub fn select_previous(&mut self, items: &[ListItem]) {
+        match self.highlight_index {
+            Some(current) => {
+                if let Some(index) = (0..current.min(items.len()))
+                    .rev()
+                    .find(|&i| !items[i].is_disabled())
+                {
+                    self.highlight_index = Some(index);
+                }
+            }
+            None => self.select_next(items),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn items() -> Vec<ListItem<'static>> {
+        vec![
+            ListItem::new("Item 0").disabled(true),
+            ListItem::new("Item 1"),
+            ListItem::new("Item 2").disabled(true),
+            ListItem::new("Item 3").disabled(true),
+            ListItem::new("Item 4"),
+        ]
+    }
+
+    #[test]
+    fn select_next_skips_disabled() {
+        let items = items();
+        let mut state = ListState::default();
+
+        state.select_next(&items);
+        assert_eq!(state.highlight_index(), Some(1));
+
+        state.select_next(&items);
+        assert_eq!(state.highlight_index(), Some(4));
+
+        // No enabled item after the last one, the highlight stays put
+        state.select_next(&items);
+        assert_eq!(state.highlight_index(), Some(4));
+    }
+
+    #[test]
+    fn select_previous_skips_disabled() {
+        let items = items();
+        let mut state = ListState::new(Some(4), 0);
+
+        state.select_previous(&items);
+        assert_eq!(state.highlight_index(), Some(1));
+
+        // Item 0 is disabled, the highlight stays put
+        state.select_previous(&items);
+        assert_eq!(state.highlight_index(), Some(1));
+    }
+
+    #[test]
+    fn select_disabled_is_noop() {
+        let items = items();
+        let mut state = ListState::new(Some(1), 0);
+
+        state.select(2, &items);
+        assert_eq!(state.highlight_index(), Some(1));
+
+        state.select(10, &items);
+        assert_eq!(state.highlight_index(), Some(1));
+
+        state.select(4, &items);
+        assert_eq!(state.highlight_index(), Some(4));
+    }
 }
@@ -0,0 +1,385 @@
+use super::reflow::{LineComposer, WordWrapper};
+
+use crate::prelude::*;
+
+/// A state for the [`LogView`] widget.
+///
+/// It owns the log lines, so it should be kept in the zellij plugin state and appended to as new
+/// lines arrive. The scroll offset is the index of the first visible log line and is resolved
+/// against the viewport on every render.
+#[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
+pub struct LogViewState {
+    /// The log lines
+    lines: Vec<String>,
+    /// The index of the first visible log line
+    offset: usize,
+    /// Whether long lines are wrapped
+    wrap: bool,
+    /// Whether the view sticks to the bottom as lines are appended
+    follow: bool,
+}
+
+impl LogViewState {
+    /// Create a new empty state, not wrapping and not following.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append a line to the log.
+    ///
+    /// Example:
+    /// ```rust
+    /// # use zellij_widgets::prelude::*;
+    /// let mut state = LogViewState::new();
+    /// state.append("server started");
+    /// assert_eq!(state.lines().len(), 1);
+    /// ```
+    pub fn append<S: Into<String>>(&mut self, line: S) {
+        self.lines.push(line.into());
+    }
+
+    /// Enable or disable wrapping of long lines.
+    pub fn wrap(&mut self, wrap: bool) {
+        self.wrap = wrap;
+    }
+
+    /// Enable or disable sticking to the bottom of the log.
+    ///
+    /// While following, every render scrolls to the last line, so manual scrolling has no effect.
+    pub fn follow(&mut self, follow: bool) {
+        self.follow = follow;
+    }
+
+    /// Returns the log lines.
+    pub fn lines(&self) -> &[String] {
+        &self.lines
+    }
+
+    /// Returns the index of the first visible log line.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Returns true if long lines are wrapped.
+    pub fn is_wrapping(&self) -> bool {
+        self.wrap
+    }
+
+    /// Returns true if the view sticks to the bottom of the log.
+    pub fn is_following(&self) -> bool {
+        self.follow
+    }
+
+    /// Scroll one line up, ensuring it doesn't go below zero.
+    pub fn scroll_up(&mut self) {
+        self.offset = self.offset.saturating_sub(1);
+    }
+
+    /// Scroll one line down, the offset is clamped to the content on the next render.
+    pub fn scroll_down(&mut self) {
+        self.offset = self.offset.saturating_add(1);
+    }
+
+    /// Returns a [`ScrollbarState`] over the log lines, positioned at the offset of the last render.
+    pub fn scrollbar_state(&self) -> ScrollbarState {
+        ScrollbarState::new(self.lines.len()).position(self.offset)
+    }
+
+    /// The largest offset that still fills a viewport of `rows` x `cols`.
+    ///
+    /// When wrapping, only the lines at the end of the log that fit in the viewport are wrapped.
+    fn max_offset(&self, rows: usize, cols: u16) -> usize {
+        if !self.wrap {
+            return self.lines.len().saturating_sub(rows);
+        }
+
+        let mut start = self.lines.len();
+        let mut used = 0;
+        for line in self.lines.iter().rev() {
+            used += LogView::wrapped_rows(line, cols);
+            if used > rows && start < self.lines.len() {
+                break;
+            }
+            start -= 1;
+        }
+        start
+    }
+}
+
+/// A widget to display a scrollable log with an integrated vertical scrollbar.
+///
+/// The log lines, wrapping and follow mode are held by [`LogViewState`], the widget only holds the
+/// presentation. The rightmost column of the area is used by the scrollbar.
+///
+/// # Example
+///
+/// ```rust
+/// use zellij_widgets::prelude::*;
+///
+/// # fn render_log(frame: &mut Frame, state: &mut LogViewState) {
+/// state.append("new line");
+/// state.follow(true);
+///
+/// let log_view = LogView::new().block(Block::default().borders(Borders::ALL).title("Logs"));
+/// frame.render_state_widget(log_view, frame.size(), state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct LogView<'a> {
+    /// The optional block of the log view.
+    block: Option<Block<'a>>,
+    /// The style of the log view.
+    style: Style,
+    /// The scrollbar rendered on the right side.
+    scrollbar: Scrollbar<'a>,
+}
+
+impl<'a> Default for LogView<'a> {
+    fn default() -> Self {
+        Self {
+            block: None,
+            style: Style::default(),
+            scrollbar: Scrollbar::new(ScrollbarOrientation::VerticalRight),
+        }
+    }
+}
+
+impl<'a> LogView<'a> {
+    /// Create a new log view.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the block of the log view.
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Set the style of the log view.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the scrollbar of the log view.
+    ///
+    /// The scrollbar is rendered in the rightmost column, so it should use the
+    /// [`ScrollbarOrientation::VerticalRight`] orientation.
+    pub fn scrollbar(mut self, scrollbar: Scrollbar<'a>) -> Self {
+        self.scrollbar = scrollbar;
+        self
+    }
+
+    /// Count the number of rows a line takes once wrapped to `cols`.
+    fn wrapped_rows(line: &str, cols: u16) -> usize {
+        let span = Span::raw(line);
+        let styled = std::iter::once((span.styled_graphemes(Style::default()), Alignment::Left));
+        let mut composer = WordWrapper::new(styled, cols, false);
+        let mut rows = 0;
+        while composer.next_line().is_some() {
+            rows += 1;
+        }
+        rows
+    }
+}
+
+impl<'a> Styled for LogView<'a> {
+    type Item = LogView<'a>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl<'a> StateWidget for LogView<'a> {
+    type State = LogViewState;
+
+    fn render(self, area: Geometry, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+
+        let log_area = match self.block {
+            Some(block) => {
+                let inner_area = block.inner(area);
+                block.render(area, buf);
+                inner_area
+            }
+            None => area,
+        };
+
+        if log_area.is_empty() {
+            return;
+        }
+
+        let (text_area, scrollbar_area) = log_area.split_horizontal_at(log_area.cols - 1);
+        let rows = text_area.rows as usize;
+
+        let max_offset = state.max_offset(rows, text_area.cols);
+        let offset = if state.follow {
+            max_offset
+        } else {
+            state.offset.min(max_offset)
+        };
+
+        // Every line takes at least one row, so the visible window never needs more lines
+        // than rows
+        let end = offset.saturating_add(rows).min(state.lines.len());
+        let lines: Vec<Line> = state.lines[offset..end]
+            .iter()
+            .map(|l| Line::raw(l.as_str()))
+            .collect();
+
+        let mut paragraph = Paragraph::new(lines);
+        if state.wrap {
+            paragraph = paragraph.wrap(Wrap { trim: false });
+        }
+        paragraph.render(text_area, buf);
+
+        state.offset = offset;
+
+        let mut scrollbar_state = state.scrollbar_state();
+        self.scrollbar
+            .render(scrollbar_area, buf, &mut scrollbar_state);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_buffer_eq;
+
+    fn state_with_lines(count: usize) -> LogViewState {
+        let mut state = LogViewState::new();
+        for i in 0..count {
+            state.append(format!("line {i}"));
+        }
+        state
+    }
+
+    #[test]
+    fn log_view_follow_stays_at_bottom() {
+        let area = Geometry::new(3, 10);
+        let mut state = state_with_lines(5);
+        state.follow(true);
+
+        let mut buffer = Buffer::empty(area);
+        LogView::new().render(area, &mut buffer, &mut state);
+        assert_eq!(state.offset(), 2);
+        assert_buffer_eq!(
+            buffer,
+            Buffer::with_lines(vec!["line 2   ▲", "line 3   █", "line 4   ▼"])
+        );
+
+        state.append("line 5");
+        let mut buffer = Buffer::empty(area);
+        LogView::new().render(area, &mut buffer, &mut state);
+        assert_eq!(state.offset(), 3);
+        assert_buffer_eq!(
+            buffer,
+            Buffer::with_lines(vec!["line 3   ▲", "line 4   █", "line 5   ▼"])
+        );
+    }
+
+    #[test]
+    fn log_view_follow_large_log() {
+        let area = Geometry::new(3, 12);
+        let mut state = state_with_lines(70_000);
+        state.follow(true);
+
+        let mut buffer = Buffer::empty(area);
+        LogView::new().render(area, &mut buffer, &mut state);
+        assert_eq!(state.offset(), 69_997);
+        assert_buffer_eq!(
+            buffer,
+            Buffer::with_lines(vec!["line 69997 ▲", "line 69998 █", "line 69999 ▼"])
+        );
+    }
+
+    #[test]
+    fn log_view_no_follow_stays_put() {
+        let area = Geometry::new(3, 10);
+        let mut state = state_with_lines(5);
+
+        let mut buffer = Buffer::empty(area);
+        LogView::new().render(area, &mut buffer, &mut state);
+        assert_eq!(state.offset(), 0);
+        assert_buffer_eq!(
+            buffer,
+            Buffer::with_lines(vec!["line 0   ▲", "line 1   █", "line 2   ▼"])
+        );
+
+        state.append("line 5");
+        let mut buffer = Buffer::empty(area);
+        LogView::new().render(area, &mut buffer, &mut state);
+        assert_eq!(state.offset(), 0);
+        assert_buffer_eq!(
+            buffer,
+            Buffer::with_lines(vec!["line 0   ▲", "line 1   █", "line 2   ▼"])
+        );
+    }
+
+    #[test]
+    fn log_view_scroll_clamped_to_content() {
+        let area = Geometry::new(3, 10);
+        let mut state = state_with_lines(5);
+        for _ in 0..10 {
+            state.scroll_down();
+        }
+
+        let mut buffer = Buffer::empty(area);
+        LogView::new().render(area, &mut buffer, &mut state);
+        assert_eq!(state.offset(), 2);
+
+        state.scroll_up();
+        let mut buffer = Buffer::empty(area);
+        LogView::new().render(area, &mut buffer, &mut state);
+        assert_eq!(state.offset(), 1);
+        assert_buffer_eq!(
+            buffer,
+            Buffer::with_lines(vec!["line 1   ▲", "line 2   █", "line 3   ▼"])
+        );
+    }
+
+    #[test]
+    fn log_view_wrap_fills_viewport() {
+        let area = Geometry::new(3, 10);
+        let mut state = LogViewState::new();
+        state.append("zzzz");
+        state.append("aaaa bbbb cccc");
+        state.append("dddd");
+        state.wrap(true);
+        state.follow(true);
+
+        let mut buffer = Buffer::empty(area);
+        LogView::new().render(area, &mut buffer, &mut state);
+
+        // The second line wraps over two rows of 9 columns, so only it and the last line fit
+        assert_eq!(state.offset(), 1);
+        assert_buffer_eq!(
+            buffer,
+            Buffer::with_lines(vec!["aaaa bbbb▲", "cccc     █", "dddd     ▼"])
+        );
+    }
+
+    #[test]
+    fn log_view_scrollbar_reflects_content() {
+        let area = Geometry::new(4, 10);
+        let mut state = state_with_lines(20);
+        state.follow(true);
+
+        let mut buffer = Buffer::empty(area);
+        LogView::new().render(area, &mut buffer, &mut state);
+
+        let scrollbar_state = state.scrollbar_state();
+        assert_eq!(scrollbar_state.content_length, 20);
+        assert_eq!(scrollbar_state.position, 16);
+        assert_buffer_eq!(
+            buffer,
+            Buffer::with_lines(vec!["line 16  ▲", "line 17  ║", "line 18  █", "line 19  ▼"])
+        );
+    }
+}
@@ -1,17 +1,23 @@
 //! This module contains all the widgets that are available in the library.
+pub use accordion::{Accordion, AccordionSection, AccordionState};
 pub use block::{Block, BorderOptions, BorderType, Borders, Padding};
 pub use erase::Erase;
 pub use gauge::Gauge;
 pub use list::{HighlightStyle, HighlightSymbol, List, ListItem, ListState};
+pub use log_view::{LogView, LogViewState};
 pub use paragraph::{Paragraph, Wrap};
+pub use scroll_view::{ScrollView, ScrollViewState};
 pub use scrollbar::{ScrollDirection, Scrollbar, ScrollbarOrientation, ScrollbarState};
 pub use tab::{Tab, TabState};
 
+mod accordion;
 mod block;
 mod erase;
 mod gauge;
 mod list;
+mod log_view;
 mod paragraph;
 mod reflow;
+mod scroll_view;
 mod scrollbar;
 mod tab;
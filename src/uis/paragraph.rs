@@ -226,10 +226,7 @@ impl<'a> Widget for Paragraph<'a> {
         }
 
         let styled = self.text.lines.iter().map(|line| {
-            let graphemes = line
-                .spans
-                .iter()
-                .flat_map(|span| span.styled_graphemes(self.style));
+            let graphemes = line.styled_graphemes(self.style);
             let alignment = line.alignment.unwrap_or(self.alignment);
             (graphemes, alignment)
         });
@@ -286,3 +283,58 @@ impl<'a> Styled for Paragraph<'a> {
         self.style(style)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn paragraph_line_style() {
+        const LINE_STYLE: Style = Style::new().fg(Color::Blue).bg(Color::White);
+
+        let text = Text::from(vec![
+            Line::from(vec![
+                Span::raw("ab"),
+                Span::styled("cd", Style::new().fg(Color::Red)),
+            ])
+            .style(LINE_STYLE),
+            Line::from("ef"),
+        ]);
+        let area = Geometry::new(2, 4);
+        let mut buf = Buffer::empty(area);
+        Paragraph::new(text).render(area, &mut buf);
+
+        // Spans without a style inherit the line style
+        assert_eq!(buf.get(0, 0).style(), LINE_STYLE);
+        assert_eq!(buf.get(1, 0).style(), LINE_STYLE);
+        // Spans only override what they set
+        assert_eq!(
+            buf.get(2, 0).style(),
+            Style::new().fg(Color::Red).bg(Color::White)
+        );
+        // Other lines are unaffected
+        assert_eq!(
+            buf.get(0, 1).style(),
+            Style::new().fg(Color::Reset).bg(Color::Reset)
+        );
+    }
+
+    #[test]
+    fn paragraph_line_style_with_wrap() {
+        const LINE_STYLE: Style = Style::new().add_modifier(Modifier::BOLD);
+
+        let text = Text::from(Line::from("ab cd").style(LINE_STYLE));
+        let area = Geometry::new(2, 3);
+        let mut buf = Buffer::empty(area);
+        Paragraph::new(text)
+            .style(Style::new().fg(Color::Green))
+            .wrap(Wrap { trim: true })
+            .render(area, &mut buf);
+
+        // The line style is applied on top of the paragraph style, even on wrapped rows
+        let expected = Style::new().fg(Color::Green).bg(Color::Reset).bold();
+        assert_eq!(buf.get(0, 0).style(), expected);
+        assert_eq!(buf.get(0, 1).symbol(), "c");
+        assert_eq!(buf.get(0, 1).style(), expected);
+    }
+}
@@ -0,0 +1,347 @@
+use crate::prelude::*;
+
+/// A state for the [`ScrollView`] widget.
+///
+/// It keeps a vertical and a horizontal [`ScrollbarState`] whose positions are also the scroll
+/// offset of the text, so the scrollbars and the text always move together. The content size and
+/// the viewport are synced on every render, scrolling is clamped so the last row or column of
+/// the content never scrolls past the bottom or right edge of the viewport.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ScrollViewState {
+    /// The vertical scroll, its position is the index of the first visible row
+    vertical: ScrollbarState,
+    /// The horizontal scroll, its position is the index of the first visible column
+    horizontal: ScrollbarState,
+    /// The (rows, cols) of the viewport, as of the last render
+    viewport: (usize, usize),
+}
+
+impl ScrollViewState {
+    /// Create a new state scrolled to the top left corner.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the scroll offset as a (y, x) tuple, in the same order as [`Paragraph::scroll`].
+    pub fn offset(&self) -> (usize, usize) {
+        (self.vertical.position, self.horizontal.position)
+    }
+
+    /// Returns the state of the vertical scrollbar.
+    pub fn vertical(&self) -> ScrollbarState {
+        self.vertical
+    }
+
+    /// Returns the state of the horizontal scrollbar.
+    pub fn horizontal(&self) -> ScrollbarState {
+        self.horizontal
+    }
+
+    /// Scroll one row up, ensuring it doesn't go below zero.
+    pub fn scroll_up(&mut self) {
+        self.vertical.prev();
+    }
+
+    /// Scroll one row down, ensuring the last row doesn't go above the bottom of the viewport.
+    pub fn scroll_down(&mut self) {
+        let max = Self::max_offset(self.vertical, self.viewport.0);
+        self.vertical.position = self.vertical.position.saturating_add(1).min(max);
+    }
+
+    /// Scroll one column left, ensuring it doesn't go below zero.
+    pub fn scroll_left(&mut self) {
+        self.horizontal.prev();
+    }
+
+    /// Scroll one column right, ensuring the last column doesn't go past the right of the
+    /// viewport.
+    pub fn scroll_right(&mut self) {
+        let max = Self::max_offset(self.horizontal, self.viewport.1);
+        self.horizontal.position = self.horizontal.position.saturating_add(1).min(max);
+    }
+
+    /// The largest offset for the given scroll, before the first render the viewport is unknown
+    /// and the offset is only clamped to the content.
+    fn max_offset(scroll: ScrollbarState, viewport: usize) -> usize {
+        scroll.content_length.saturating_sub(viewport.max(1))
+    }
+
+    /// Sync the content size and viewport, clamping the offsets to them.
+    ///
+    /// The horizontal content is clamped to `u16::MAX` columns, the most a [`Paragraph`] can
+    /// scroll to.
+    fn sync(&mut self, content: (usize, usize), viewport: (usize, usize)) {
+        self.viewport = viewport;
+        self.vertical.content_length = content.0;
+        self.horizontal.content_length = content.1.min(u16::MAX as usize);
+        self.vertical.position = self
+            .vertical
+            .position
+            .min(Self::max_offset(self.vertical, viewport.0));
+        self.horizontal.position = self
+            .horizontal
+            .position
+            .min(Self::max_offset(self.horizontal, viewport.1));
+    }
+}
+
+/// A widget to display a text scrollable in both directions, with a vertical and a horizontal
+/// scrollbar.
+///
+/// The rows of the text from the vertical offset of the [`ScrollViewState`] are rendered with a
+/// [`Paragraph`] scrolled by the horizontal offset, the vertical scrollbar takes the rightmost
+/// column and the horizontal one the bottom row.
+///
+/// # Example
+///
+/// ```rust
+/// use zellij_widgets::prelude::*;
+///
+/// # fn render_scroll_view(frame: &mut Frame, state: &mut ScrollViewState) {
+/// // e.g. on `Key::Down`
+/// state.scroll_down();
+///
+/// let scroll_view = ScrollView::new("A long text\nover multiple lines")
+///     .block(Block::default().borders(Borders::ALL).title("Scroll"));
+/// frame.render_state_widget(scroll_view, frame.size(), state);
+/// # }
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ScrollView<'a> {
+    /// The text to display.
+    text: Text<'a>,
+    /// The optional block of the scroll view.
+    block: Option<Block<'a>>,
+    /// The style of the scroll view.
+    style: Style,
+    /// The scrollbar rendered on the right side.
+    vertical_scrollbar: Scrollbar<'a>,
+    /// The scrollbar rendered at the bottom.
+    horizontal_scrollbar: Scrollbar<'a>,
+}
+
+impl<'a> ScrollView<'a> {
+    /// Create a new scroll view with the given text.
+    pub fn new<T>(text: T) -> Self
+    where
+        T: Into<Text<'a>>,
+    {
+        Self {
+            text: text.into(),
+            block: None,
+            style: Style::default(),
+            vertical_scrollbar: Scrollbar::new(ScrollbarOrientation::VerticalRight),
+            horizontal_scrollbar: Scrollbar::new(ScrollbarOrientation::HorizontalBottom),
+        }
+    }
+
+    /// Set the block of the scroll view.
+    pub fn block(mut self, block: Block<'a>) -> Self {
+        self.block = Some(block);
+        self
+    }
+
+    /// Set the style of the scroll view.
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Set the vertical scrollbar, it should use the [`ScrollbarOrientation::VerticalRight`]
+    /// orientation.
+    pub fn vertical_scrollbar(mut self, scrollbar: Scrollbar<'a>) -> Self {
+        self.vertical_scrollbar = scrollbar;
+        self
+    }
+
+    /// Set the horizontal scrollbar, it should use the
+    /// [`ScrollbarOrientation::HorizontalBottom`] orientation.
+    pub fn horizontal_scrollbar(mut self, scrollbar: Scrollbar<'a>) -> Self {
+        self.horizontal_scrollbar = scrollbar;
+        self
+    }
+}
+
+impl<'a> Styled for ScrollView<'a> {
+    type Item = ScrollView<'a>;
+
+    fn style(&self) -> Style {
+        self.style
+    }
+
+    fn set_style(self, style: Style) -> Self::Item {
+        self.style(style)
+    }
+}
+
+impl<'a> StateWidget for ScrollView<'a> {
+    type State = ScrollViewState;
+
+    fn render(self, area: Geometry, buf: &mut Buffer, state: &mut Self::State) {
+        buf.set_style(area, self.style);
+
+        let view_area = match self.block {
+            Some(block) => {
+                let inner_area = block.inner(area);
+                block.render(area, buf);
+                inner_area
+            }
+            None => area,
+        };
+
+        if view_area.cols < 2 || view_area.rows < 2 {
+            return;
+        }
+
+        let (area, horizontal_area) = view_area.split_vertical_at(view_area.rows - 1);
+        let (text_area, vertical_area) = area.split_horizontal_at(area.cols - 1);
+        let horizontal_area = Geometry {
+            cols: text_area.cols,
+            ..horizontal_area
+        };
+
+        state.sync(
+            (self.text.height(), self.text.width()),
+            (text_area.rows as usize, text_area.cols as usize),
+        );
+
+        // Skip the rows above the viewport rather than scrolling the paragraph, whose vertical
+        // scroll is limited to u16
+        let (y, x) = state.offset();
+        let lines: Vec<Line> = self
+            .text
+            .lines
+            .into_iter()
+            .skip(y)
+            .take(text_area.rows as usize)
+            .collect();
+        Paragraph::new(lines)
+            .scroll((0, x as u16))
+            .render(text_area, buf);
+
+        self.vertical_scrollbar
+            .render(vertical_area, buf, &mut state.vertical());
+        self.horizontal_scrollbar
+            .render(horizontal_area, buf, &mut state.horizontal());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::assert_buffer_eq;
+
+    fn text() -> Text<'static> {
+        Text::from("line 0..\nline 1..\nline 2..\nline 3..\nline 4..")
+    }
+
+    fn render(state: &mut ScrollViewState) -> Buffer {
+        let area = Geometry::new(4, 6);
+        let mut buffer = Buffer::empty(area);
+        ScrollView::new(text()).render(area, &mut buffer, state);
+        buffer
+    }
+
+    #[test]
+    fn scroll_view_renders_text_and_scrollbars() {
+        let mut state = ScrollViewState::new();
+        let buffer = render(&mut state);
+
+        assert_buffer_eq!(
+            buffer,
+            Buffer::with_lines(vec!["line ▲", "line █", "line ▼", "◄█══► "])
+        );
+        assert_eq!(state.vertical().content_length, 5);
+        assert_eq!(state.horizontal().content_length, 8);
+    }
+
+    #[test]
+    fn scroll_view_scroll_down_clamped() {
+        let mut state = ScrollViewState::new();
+        render(&mut state);
+
+        for _ in 0..10 {
+            state.scroll_down();
+        }
+        // 5 rows of content in a 3 rows viewport
+        assert_eq!(state.offset(), (2, 0));
+        assert_eq!(state.vertical().position, 2);
+
+        let buffer = render(&mut state);
+        assert_buffer_eq!(
+            buffer,
+            Buffer::with_lines(vec!["line ▲", "line █", "line ▼", "◄█══► "])
+        );
+        assert_eq!(state.offset(), (2, 0));
+
+        state.scroll_up();
+        assert_eq!(state.offset(), (1, 0));
+        assert_eq!(state.vertical().position, 1);
+    }
+
+    #[test]
+    fn scroll_view_scroll_right_clamped() {
+        let mut state = ScrollViewState::new();
+        render(&mut state);
+
+        for _ in 0..10 {
+            state.scroll_right();
+        }
+        // 8 columns of content in a 5 columns viewport
+        assert_eq!(state.offset(), (0, 3));
+        assert_eq!(state.horizontal().position, 3);
+
+        let buffer = render(&mut state);
+        assert_buffer_eq!(
+            buffer,
+            Buffer::with_lines(vec!["e 0..▲", "e 1..█", "e 2..▼", "◄═█═► "])
+        );
+
+        state.scroll_left();
+        assert_eq!(state.offset(), (0, 2));
+    }
+
+    #[test]
+    fn scroll_view_content_shrinks() {
+        let mut state = ScrollViewState::new();
+        render(&mut state);
+        for _ in 0..10 {
+            state.scroll_down();
+        }
+
+        // The offset is clamped again when the content gets smaller
+        let area = Geometry::new(4, 6);
+        let mut buffer = Buffer::empty(area);
+        ScrollView::new("one\ntwo\nthree\nfour").render(area, &mut buffer, &mut state);
+        assert_eq!(state.offset(), (1, 0));
+        assert_eq!(state.vertical().position, 1);
+        assert_buffer_eq!(
+            buffer,
+            Buffer::with_lines(vec!["two  ▲", "three█", "four ▼", "◄██═► "])
+        );
+    }
+
+    #[test]
+    fn scroll_view_large_content() {
+        let text: Vec<Line> = (0..70_000)
+            .map(|i| Line::from(format!("row {i}")))
+            .collect();
+        let area = Geometry::new(4, 10);
+        let mut state = ScrollViewState::new();
+        ScrollView::new(text.clone()).render(area, &mut Buffer::empty(area), &mut state);
+        for _ in 0..70_000 {
+            state.scroll_down();
+        }
+
+        let mut buffer = Buffer::empty(area);
+        ScrollView::new(text).render(area, &mut buffer, &mut state);
+
+        // The text is drawn from the offset reported to the scrollbar
+        assert_eq!(state.offset(), (69_997, 0));
+        assert_eq!(state.vertical().position, 69_997);
+        assert_buffer_eq!(
+            buffer,
+            Buffer::with_lines(vec!["row 69997▲", "row 69998█", "row 69999▼", "◄█████══► ",])
+        );
+    }
+}
@@ -39,6 +39,30 @@ impl TabState {
     pub fn reset_index(&mut self) {
         self.selected = 0;
     }
+
+    /// Select the tab whose title matches `wanted`, keeping the selection stable when tabs are
+    /// reordered or filtered.
+    ///
+    /// When found, the index is set to the matching position and the number of tabs is synced to
+    /// `titles`. The state is left unchanged if no title matches.
+    ///
+    /// # Example
+    /// ```rust
+    /// # use zellij_widgets::prelude::*;
+    /// let mut state = TabState::new(3);
+    /// state.select_title(&["Logs", "Config", "Help"], "Help");
+    /// assert_eq!(state.selected, 2);
+    ///
+    /// state.select_title(&["Help", "Logs"], "Help");
+    /// assert_eq!(state.selected, 0);
+    /// assert_eq!(state.len, 2);
+    /// ```
+    pub fn select_title(&mut self, titles: &[&str], wanted: &str) {
+        if let Some(index) = titles.iter().position(|title| *title == wanted) {
+            self.selected = index;
+            self.len = titles.len();
+        }
+    }
 }
 
 #[derive(Debug, Default, Clone, Eq, PartialEq, Hash)]
@@ -198,6 +222,7 @@ mod tests {
                     sub_modifier: Modifier::empty(),
                 },
             }]),
+            style: Style::default(),
             alignment: None,
         };
 
@@ -211,6 +236,7 @@ mod tests {
                     sub_modifier: Modifier::empty(),
                 },
             }]),
+            style: Style::default(),
             alignment: None,
         };
         assert_eq!(tab.title, vec![line_1, line_2]);
@@ -243,6 +269,31 @@ mod tests {
         assert_eq!(tab.highlight_style, style);
     }
 
+    #[test]
+    fn tab_state_select_title() {
+        let mut state = TabState::new(3);
+        state.select_title(&["Tab1", "Tab2", "Tab3"], "Tab2");
+        assert_eq!(state.selected, 1);
+
+        // The tab list is reordered, the selection follows the title
+        state.select_title(&["Tab2", "Tab3", "Tab1"], "Tab2");
+        assert_eq!(state.selected, 0);
+
+        // The tab list is filtered
+        state.select_title(&["Tab1", "Tab3"], "Tab3");
+        assert_eq!(state.selected, 1);
+        assert_eq!(state.len, 2);
+    }
+
+    #[test]
+    fn tab_state_select_missing_title() {
+        let mut state = TabState::new(3);
+        state.next();
+        state.select_title(&["Tab1", "Tab2", "Tab3"], "Missing");
+        assert_eq!(state.selected, 1);
+        assert_eq!(state.len, 3);
+    }
+
     #[test]
     fn tab_state_widget() {
         let tab = Tab::new(vec!["Tab1", "Tab2"]);